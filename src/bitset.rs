@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+macro_rules! fixed_bitset {
+    ($name:ident, $subsets:ident, $word:ty) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($word);
+
+        impl $name {
+            pub const CAPACITY: usize = <$word>::BITS as usize;
+
+            pub fn new() -> Self {
+                Self(0)
+            }
+
+            pub fn from_bits(bits: $word) -> Self {
+                Self(bits)
+            }
+
+            pub fn bits(&self) -> $word {
+                self.0
+            }
+
+            pub fn full(len: usize) -> Self {
+                assert!(len <= Self::CAPACITY);
+                if len == Self::CAPACITY {
+                    Self(<$word>::MAX)
+                } else {
+                    Self((1 << len) - 1)
+                }
+            }
+
+            pub fn contains(&self, idx: usize) -> bool {
+                idx < Self::CAPACITY && self.0 & (1 << idx) != 0
+            }
+
+            pub fn insert(&mut self, idx: usize) -> bool {
+                assert!(idx < Self::CAPACITY);
+                let new = !self.contains(idx);
+                self.0 |= 1 << idx;
+                new
+            }
+
+            pub fn remove(&mut self, idx: usize) -> bool {
+                let present = self.contains(idx);
+                if present {
+                    self.0 &= !(1 << idx);
+                }
+                present
+            }
+
+            pub fn with(&self, idx: usize) -> Self {
+                let mut out = *self;
+                out.insert(idx);
+                out
+            }
+
+            pub fn without(&self, idx: usize) -> Self {
+                let mut out = *self;
+                out.remove(idx);
+                out
+            }
+
+            pub fn len(&self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn union(&self, other: &Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            pub fn intersection(&self, other: &Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            pub fn difference(&self, other: &Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            pub fn is_subset(&self, other: &Self) -> bool {
+                self.0 & !other.0 == 0
+            }
+
+            pub fn iter(&self) -> impl Iterator<Item = usize> {
+                let mut bits = self.0;
+                std::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let idx = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(idx)
+                })
+            }
+
+            pub fn subsets(&self) -> $subsets {
+                $subsets {
+                    mask: self.0,
+                    next: Some(self.0),
+                }
+            }
+        }
+
+        impl FromIterator<usize> for $name {
+            fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+                let mut out = Self::new();
+                for idx in iter {
+                    out.insert(idx);
+                }
+                out
+            }
+        }
+
+        pub struct $subsets {
+            mask: $word,
+            next: Option<$word>,
+        }
+
+        impl Iterator for $subsets {
+            type Item = $name;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let current = self.next?;
+                self.next = if current == 0 {
+                    None
+                } else {
+                    Some((current - 1) & self.mask)
+                };
+                Some($name(current))
+            }
+        }
+    };
+}
+
+fixed_bitset!(BitSet64, Subsets64, u64);
+fixed_bitset!(BitSet128, Subsets128, u128);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(bits.div_ceil(64)),
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        self.words
+            .get(idx / 64)
+            .is_some_and(|w| w & (1 << (idx % 64)) != 0)
+    }
+
+    pub fn insert(&mut self, idx: usize) -> bool {
+        let word = idx / 64;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let mask = 1 << (idx % 64);
+        let new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        new
+    }
+
+    pub fn remove(&mut self, idx: usize) -> bool {
+        let present = self.contains(idx);
+        if present {
+            self.words[idx / 64] &= !(1 << (idx % 64));
+            self.trim();
+        }
+        present
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (long, short) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut out = long.clone();
+        for (word, other_word) in out.words.iter_mut().zip(short.words.iter()) {
+            *word |= other_word;
+        }
+        out
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut out = Self {
+            words: self
+                .words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(a, b)| a & b)
+                .collect(),
+        };
+        out.trim();
+        out
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for (word, other_word) in out.words.iter_mut().zip(other.words.iter()) {
+            *word &= !other_word;
+        }
+        out.trim();
+        out
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(idx, word)| word & !other.words.get(idx).copied().unwrap_or(0) == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_idx, word)| {
+            let mut bits = *word;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(word_idx * 64 + bit)
+            })
+        })
+    }
+
+    pub fn subsets(&self) -> impl Iterator<Item = BitSet> + '_ {
+        let members: Vec<usize> = self.iter().collect();
+        assert!(members.len() < 64, "too many members to enumerate subsets");
+        (0..1u64 << members.len()).map(move |selection| {
+            BitSet64::from_bits(selection)
+                .iter()
+                .map(|idx| members[idx])
+                .collect()
+        })
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut out = Self::new();
+        for idx in iter {
+            out.insert(idx);
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Interner<L> {
+    indices: HashMap<L, usize>,
+    labels: Vec<L>,
+}
+
+impl<L> Default for Interner<L> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<L: Hash + Eq + Clone> Interner<L> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, label: &L) -> usize {
+        if let Some(idx) = self.indices.get(label) {
+            return *idx;
+        }
+        let idx = self.labels.len();
+        self.indices.insert(label.clone(), idx);
+        self.labels.push(label.clone());
+        idx
+    }
+
+    pub fn get(&self, label: &L) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    pub fn label(&self, idx: usize) -> Option<&L> {
+        self.labels.get(idx)
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+}
@@ -0,0 +1,119 @@
+use crate::grid::{CountingMap, Location, Map, UnboundLocation};
+
+#[derive(Debug, Clone)]
+struct Segment {
+    start: i64,
+    len: i64,
+}
+
+fn segments(mut values: Vec<i64>) -> Vec<Segment> {
+    values.sort_unstable();
+    values.dedup();
+    let (Some(first), Some(last)) = (values.first().copied(), values.last().copied()) else {
+        return Vec::new();
+    };
+
+    let mut out = vec![Segment {
+        start: first - 1,
+        len: 1,
+    }];
+    for (idx, value) in values.iter().enumerate() {
+        out.push(Segment {
+            start: *value,
+            len: 1,
+        });
+        if let Some(next) = values.get(idx + 1)
+            && next - value > 1
+        {
+            out.push(Segment {
+                start: value + 1,
+                len: next - value - 1,
+            });
+        }
+    }
+    out.push(Segment {
+        start: last + 1,
+        len: 1,
+    });
+    out
+}
+
+fn find_segment(segments: &[Segment], value: i64) -> Option<usize> {
+    let idx = segments
+        .partition_point(|s| s.start <= value)
+        .checked_sub(1)?;
+    (value < segments[idx].start + segments[idx].len).then_some(idx)
+}
+
+#[derive(Debug, Clone)]
+pub struct CoordinateCompressor {
+    rows: Vec<Segment>,
+    cols: Vec<Segment>,
+}
+
+impl CoordinateCompressor {
+    pub fn new<'a, I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = &'a UnboundLocation>,
+    {
+        let (rows, cols): (Vec<i64>, Vec<i64>) = points.into_iter().map(|p| (p.0, p.1)).unzip();
+        Self {
+            rows: segments(rows),
+            cols: segments(cols),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols.len()
+    }
+
+    pub fn compress(&self, location: &UnboundLocation) -> Option<Location> {
+        Some(Location(
+            find_segment(&self.rows, location.0)?,
+            find_segment(&self.cols, location.1)?,
+        ))
+    }
+
+    pub fn expand(&self, location: &Location) -> (UnboundLocation, UnboundLocation) {
+        let row = &self.rows[location.0];
+        let col = &self.cols[location.1];
+        (
+            UnboundLocation(row.start, col.start),
+            UnboundLocation(row.start + row.len - 1, col.start + col.len - 1),
+        )
+    }
+
+    pub fn weight(&self, location: &Location) -> u64 {
+        (self.rows[location.0].len * self.cols[location.1].len) as u64
+    }
+
+    pub fn to_map<T, F>(&self, init: F) -> Map<T>
+    where
+        F: Fn(Location) -> T,
+    {
+        Map::from_dimensions(self.rows(), self.cols(), init)
+    }
+
+    pub fn path(&self, from: &UnboundLocation, to: &UnboundLocation) -> Option<Vec<Location>> {
+        let from = self.compress(from)?;
+        let to = self.compress(to)?;
+        if from.0 != to.0 && from.1 != to.1 {
+            return None;
+        }
+        let mut out = Vec::new();
+        for row in from.0.min(to.0)..=from.0.max(to.0) {
+            for col in from.1.min(to.1)..=from.1.max(to.1) {
+                out.push(Location(row, col));
+            }
+        }
+        Some(out)
+    }
+
+    pub fn weighted_area(&self, marked: &CountingMap) -> u64 {
+        marked.marked().map(|loc| self.weight(&loc)).sum()
+    }
+}
@@ -0,0 +1,66 @@
+pub mod compress;
+pub mod rectilinear;
+
+use crate::grid::{Direction, UnboundLocation};
+use crate::math::gcd;
+
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    vertices: Vec<UnboundLocation>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<UnboundLocation>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn from_moves<I>(start: UnboundLocation, moves: I) -> Self
+    where
+        I: IntoIterator<Item = (Direction, usize)>,
+    {
+        let mut vertices = vec![start];
+        for (direction, distance) in moves {
+            let last = vertices.last().expect("vertices starts non-empty");
+            vertices.push(last.go_direction(&direction, distance));
+        }
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[UnboundLocation] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&UnboundLocation, &UnboundLocation)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    pub fn boundary_length(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd(b.0 - a.0, b.1 - a.1) as u64)
+            .sum()
+    }
+
+    pub fn double_area(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum::<i64>()
+            .unsigned_abs()
+    }
+
+    pub fn area(&self) -> u64 {
+        self.double_area() / 2
+    }
+
+    pub fn interior_points(&self) -> u64 {
+        (self.double_area() + 2).saturating_sub(self.boundary_length()) / 2
+    }
+
+    pub fn enclosed_points(&self) -> u64 {
+        self.interior_points() + self.boundary_length()
+    }
+}
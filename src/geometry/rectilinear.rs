@@ -0,0 +1,171 @@
+use crate::geometry::Polygon;
+use crate::grid::UnboundLocation;
+use crate::ranges::{InclusiveRange, merge_inclusive_ranges};
+use std::collections::BTreeMap;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RectilinearError {
+    #[error("Edge from {0:?} to {1:?} is not axis aligned")]
+    NotAxisAligned(UnboundLocation, UnboundLocation),
+    #[error("Polygon needs at least 4 vertices found {0}")]
+    TooFewVertices(usize),
+}
+
+type Intervals = Vec<InclusiveRange<i64>>;
+
+fn covers(intervals: &Intervals, start: i64, end: i64) -> bool {
+    let idx = intervals.partition_point(|r| r.start <= start);
+    idx > 0 && intervals[idx - 1].end >= end
+}
+
+fn add_edge(edges: &mut BTreeMap<i64, Intervals>, key: i64, a: i64, b: i64) {
+    edges.entry(key).or_default().push(InclusiveRange {
+        start: a.min(b),
+        end: a.max(b),
+    });
+}
+
+#[derive(Debug, Clone)]
+pub struct RectilinearPolygon {
+    rows: Vec<i64>,
+    row_cover: Vec<Intervals>,
+    band_cover: Vec<Intervals>,
+    horizontal: BTreeMap<i64, Intervals>,
+    vertical: BTreeMap<i64, Intervals>,
+}
+
+impl RectilinearPolygon {
+    pub fn new(polygon: &Polygon) -> Result<Self, RectilinearError> {
+        if polygon.vertices().len() < 4 {
+            return Err(RectilinearError::TooFewVertices(polygon.vertices().len()));
+        }
+
+        let mut horizontal = BTreeMap::new();
+        let mut vertical = BTreeMap::new();
+        for (a, b) in polygon.edges() {
+            if a.0 == b.0 {
+                add_edge(&mut horizontal, a.0, a.1, b.1);
+            } else if a.1 == b.1 {
+                add_edge(&mut vertical, a.1, a.0, b.0);
+            } else {
+                return Err(RectilinearError::NotAxisAligned(a.clone(), b.clone()));
+            }
+        }
+
+        let mut rows: Vec<i64> = polygon.vertices().iter().map(|v| v.0).collect();
+        rows.sort_unstable();
+        rows.dedup();
+
+        let band_cover: Vec<Intervals> = rows
+            .windows(2)
+            .map(|band| {
+                let crossing: Vec<i64> = vertical
+                    .iter()
+                    .flat_map(|(col, edges)| {
+                        edges
+                            .iter()
+                            .filter(|e| e.start <= band[0] && e.end >= band[1])
+                            .map(move |_| *col)
+                    })
+                    .collect();
+                merge_touching(
+                    crossing
+                        .chunks_exact(2)
+                        .map(|pair| InclusiveRange {
+                            start: pair[0],
+                            end: pair[1],
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let row_cover = (0..rows.len())
+            .map(|idx| {
+                let mut intervals = Vec::new();
+                if idx > 0 {
+                    intervals.extend(band_cover[idx - 1].iter().cloned());
+                }
+                if let Some(below) = band_cover.get(idx) {
+                    intervals.extend(below.iter().cloned());
+                }
+                if let Some(edges) = horizontal.get(&rows[idx]) {
+                    intervals.extend(edges.iter().cloned());
+                }
+                merge_touching(intervals)
+            })
+            .collect();
+
+        for edges in horizontal.values_mut().chain(vertical.values_mut()) {
+            *edges = merge_touching(std::mem::take(edges));
+        }
+
+        Ok(Self {
+            rows,
+            row_cover,
+            band_cover,
+            horizontal,
+            vertical,
+        })
+    }
+
+    fn cover_for_row(&self, row: i64) -> Option<&Intervals> {
+        match self.rows.binary_search(&row) {
+            Ok(idx) => Some(&self.row_cover[idx]),
+            Err(0) => None,
+            Err(idx) => self.band_cover.get(idx - 1),
+        }
+    }
+
+    pub fn on_boundary(&self, point: &UnboundLocation) -> bool {
+        let on = |edges: &BTreeMap<i64, Intervals>, key: i64, value: i64| {
+            edges
+                .get(&key)
+                .is_some_and(|intervals| covers(intervals, value, value))
+        };
+        on(&self.horizontal, point.0, point.1) || on(&self.vertical, point.1, point.0)
+    }
+
+    pub fn contains(&self, point: &UnboundLocation) -> bool {
+        self.cover_for_row(point.0)
+            .is_some_and(|intervals| covers(intervals, point.1, point.1))
+    }
+
+    pub fn contains_interior(&self, point: &UnboundLocation) -> bool {
+        self.contains(point) && !self.on_boundary(point)
+    }
+
+    pub fn contains_rect(&self, a: &UnboundLocation, b: &UnboundLocation) -> bool {
+        let (top, bottom) = (a.0.min(b.0), a.0.max(b.0));
+        let (left, right) = (a.1.min(b.1), a.1.max(b.1));
+        let fits = |intervals: Option<&Intervals>| {
+            intervals.is_some_and(|intervals| covers(intervals, left, right))
+        };
+
+        let mut row = top;
+        while row <= bottom {
+            if !fits(self.cover_for_row(row)) {
+                return false;
+            }
+            row = match self.rows.binary_search(&row) {
+                Ok(_) => row + 1,
+                Err(idx) => match self.rows.get(idx) {
+                    Some(next) => *next,
+                    None => return false,
+                },
+            };
+        }
+        true
+    }
+}
+
+fn merge_touching(intervals: Intervals) -> Intervals {
+    let mut out: Intervals = Vec::new();
+    for range in merge_inclusive_ranges(intervals) {
+        match out.last_mut() {
+            Some(last) if last.end + 1 >= range.start => last.end = last.end.max(range.end),
+            _ => out.push(range),
+        }
+    }
+    out
+}
@@ -0,0 +1,124 @@
+use crate::grid::{Direction, Location, Map};
+
+const DIRECTIONS: [(Option<Direction>, Option<Direction>); 8] = [
+    (Some(Direction::North), None),
+    (None, Some(Direction::East)),
+    (Some(Direction::South), None),
+    (None, Some(Direction::West)),
+    (Some(Direction::North), Some(Direction::East)),
+    (Some(Direction::South), Some(Direction::East)),
+    (Some(Direction::South), Some(Direction::West)),
+    (Some(Direction::North), Some(Direction::West)),
+];
+
+pub enum Neighbourhood<T> {
+    Orthogonal,
+    Moore,
+    LineOfSight(fn(&T) -> bool),
+}
+
+impl<T> Map<T> {
+    pub fn neighbours<'a>(
+        &'a self,
+        location: &Location,
+        neighbourhood: &Neighbourhood<T>,
+        out: &mut Vec<&'a T>,
+    ) {
+        out.clear();
+        let directions = match neighbourhood {
+            Neighbourhood::Orthogonal => &DIRECTIONS[..4],
+            Neighbourhood::Moore | Neighbourhood::LineOfSight(_) => &DIRECTIONS[..],
+        };
+        for (x_direction, y_direction) in directions {
+            let mut ray =
+                self.iter_direction(location.clone(), x_direction.clone(), y_direction.clone());
+            let found = match neighbourhood {
+                Neighbourhood::LineOfSight(transparent) => ray.find(|(_, v)| !transparent(v)),
+                _ => ray.next(),
+            };
+            if let Some((_, value)) = found {
+                out.push(value);
+            }
+        }
+    }
+
+    pub fn step_into<F>(&self, out: &mut Map<T>, neighbourhood: &Neighbourhood<T>, mut rule: F)
+    where
+        F: FnMut(&T, &[&T]) -> T,
+    {
+        let mut neighbours = Vec::with_capacity(8);
+        for (row_num, row) in self.0.iter().enumerate() {
+            for (col_num, value) in row.iter().enumerate() {
+                self.neighbours(&Location(row_num, col_num), neighbourhood, &mut neighbours);
+                out.0[row_num][col_num] = rule(value, &neighbours);
+            }
+        }
+    }
+
+    pub fn step<F>(&self, neighbourhood: &Neighbourhood<T>, rule: F) -> Map<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &[&T]) -> T,
+    {
+        let mut out = self.clone();
+        self.step_into(&mut out, neighbourhood, rule);
+        out
+    }
+}
+
+pub struct Automaton<T> {
+    current: Map<T>,
+    next: Map<T>,
+    neighbourhood: Neighbourhood<T>,
+    generation: usize,
+}
+
+impl<T: Clone> Automaton<T> {
+    pub fn new(map: Map<T>, neighbourhood: Neighbourhood<T>) -> Self {
+        Self {
+            next: map.clone(),
+            current: map,
+            neighbourhood,
+            generation: 0,
+        }
+    }
+
+    pub fn map(&self) -> &Map<T> {
+        &self.current
+    }
+
+    pub fn into_map(self) -> Map<T> {
+        self.current
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step<F>(&mut self, rule: F)
+    where
+        F: FnMut(&T, &[&T]) -> T,
+    {
+        self.current
+            .step_into(&mut self.next, &self.neighbourhood, rule);
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    pub fn run_until_stable<F>(&mut self, mut rule: F) -> usize
+    where
+        T: PartialEq,
+        F: FnMut(&T, &[&T]) -> T,
+    {
+        let start = self.generation;
+        loop {
+            self.current
+                .step_into(&mut self.next, &self.neighbourhood, &mut rule);
+            if self.current == self.next {
+                return self.generation - start;
+            }
+            std::mem::swap(&mut self.current, &mut self.next);
+            self.generation += 1;
+        }
+    }
+}
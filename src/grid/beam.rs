@@ -0,0 +1,41 @@
+use crate::grid::bits::DirectionSet;
+use crate::grid::{CountingMap, Direction, Location, Map};
+
+impl<T> Map<T> {
+    pub fn trace_beam<F, I>(&self, start: &Location, heading: Direction, redirect: F) -> CountingMap
+    where
+        F: Fn(&T, &Direction) -> I,
+        I: IntoIterator<Item = Direction>,
+    {
+        let mut energized = CountingMap::from(self);
+        let mut visited: Map<DirectionSet> = self.transform(|_, _| DirectionSet::new());
+        let mut stack = vec![(start.clone(), heading)];
+        while let Some((location, heading)) = stack.pop() {
+            if !visited.get_mut(&location).insert(&heading) {
+                continue;
+            }
+            energized.mark(&location);
+
+            for next_heading in redirect(self.get(&location), &heading) {
+                if let Some(next) = self.go_direction(&location, &next_heading) {
+                    stack.push((next, next_heading));
+                }
+            }
+        }
+        energized
+    }
+
+    pub fn trace_edges<F, I>(&self, redirect: F) -> Vec<((Location, Direction), usize)>
+    where
+        F: Fn(&T, &Direction) -> I,
+        I: IntoIterator<Item = Direction>,
+    {
+        self.get_edges()
+            .into_iter()
+            .map(|(location, heading)| {
+                let energized = self.trace_beam(&location, heading.clone(), &redirect);
+                ((location, heading), energized.unique())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,242 @@
+use crate::grid::{CountingMap, Direction, Location, Map};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    pub fn all() -> Self {
+        Self(0b1111)
+    }
+
+    pub fn contains(&self, direction: &Direction) -> bool {
+        self.0 & (1 << direction.idx()) != 0
+    }
+
+    pub fn insert(&mut self, direction: &Direction) -> bool {
+        let new = !self.contains(direction);
+        self.0 |= 1 << direction.idx();
+        new
+    }
+
+    pub fn remove(&mut self, direction: &Direction) -> bool {
+        let present = self.contains(direction);
+        self.0 &= !(1 << direction.idx());
+        present
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
+        Direction::all()
+            .iter()
+            .filter(|d| self.contains(d))
+            .cloned()
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        let mut out = Self::new();
+        for direction in iter {
+            out.insert(&direction);
+        }
+        out
+    }
+}
+
+impl CountingMap {
+    pub fn clear(&mut self) {
+        for row in self.0.0.iter_mut() {
+            row.fill(false);
+        }
+        self.1 = 0;
+    }
+
+    fn combine<F>(&mut self, other: &CountingMap, op: F)
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        let mut count = 0;
+        for (row, other_row) in self.0.0.iter_mut().zip(other.0.0.iter()) {
+            for (v, o) in row.iter_mut().zip(other_row.iter()) {
+                *v = op(*v, *o);
+                count += *v as usize;
+            }
+        }
+        self.1 = count;
+    }
+
+    pub fn union_with(&mut self, other: &CountingMap) {
+        self.combine(other, |a, b| a || b)
+    }
+
+    pub fn intersect_with(&mut self, other: &CountingMap) {
+        self.combine(other, |a, b| a && b)
+    }
+
+    pub fn intersection_count(&self, other: &CountingMap) -> usize {
+        self.0
+            .0
+            .iter()
+            .zip(other.0.0.iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).filter(|(a, b)| **a && **b).count())
+            .sum()
+    }
+
+    pub fn union_count(&self, other: &CountingMap) -> usize {
+        self.unique() + other.unique() - self.intersection_count(other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitCountingMap {
+    bits: Vec<u64>,
+    rows: usize,
+    cols: usize,
+    count: usize,
+}
+
+impl BitCountingMap {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            bits: vec![0; (rows * cols).div_ceil(64)],
+            rows,
+            cols,
+            count: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn position(&self, location: &Location) -> (usize, u64) {
+        assert!(location.0 < self.rows && location.1 < self.cols);
+        let idx = location.0 * self.cols + location.1;
+        (idx / 64, 1 << (idx % 64))
+    }
+
+    pub fn get(&self, location: &Location) -> bool {
+        let (word, mask) = self.position(location);
+        self.bits[word] & mask != 0
+    }
+
+    pub fn mark(&mut self, location: &Location) -> bool {
+        let (word, mask) = self.position(location);
+        let new = self.bits[word] & mask == 0;
+        if new {
+            self.bits[word] |= mask;
+            self.count += 1;
+        }
+        new
+    }
+
+    pub fn unmark(&mut self, location: &Location) -> bool {
+        let (word, mask) = self.position(location);
+        let present = self.bits[word] & mask != 0;
+        if present {
+            self.bits[word] &= !mask;
+            self.count -= 1;
+        }
+        present
+    }
+
+    pub fn unique(&self) -> usize {
+        self.count
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+        self.count = 0;
+    }
+
+    pub fn marked(&self) -> impl Iterator<Item = Location> + '_ {
+        self.bits
+            .iter()
+            .enumerate()
+            .flat_map(move |(word_idx, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    let idx = word_idx * 64 + bit;
+                    Some(Location(idx / self.cols, idx % self.cols))
+                })
+            })
+    }
+
+    fn combine<F>(&mut self, other: &BitCountingMap, op: F)
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        assert_eq!((self.rows, self.cols), (other.rows, other.cols));
+        let mut count = 0;
+        for (word, other_word) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *word = op(*word, *other_word);
+            count += word.count_ones() as usize;
+        }
+        self.count = count;
+    }
+
+    pub fn union_with(&mut self, other: &BitCountingMap) {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersect_with(&mut self, other: &BitCountingMap) {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn intersection_count(&self, other: &BitCountingMap) -> usize {
+        self.bits
+            .iter()
+            .zip(other.bits.iter())
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    pub fn union_count(&self, other: &BitCountingMap) -> usize {
+        self.bits
+            .iter()
+            .zip(other.bits.iter())
+            .map(|(a, b)| (a | b).count_ones() as usize)
+            .sum()
+    }
+}
+
+impl<T> From<&Map<T>> for BitCountingMap {
+    fn from(other: &Map<T>) -> Self {
+        Self::new(other.0.len(), other.width().unwrap_or(0))
+    }
+}
+
+impl From<&CountingMap> for BitCountingMap {
+    fn from(other: &CountingMap) -> Self {
+        let mut out = Self::from(&other.0);
+        for location in other.marked() {
+            out.mark(&location);
+        }
+        out
+    }
+}
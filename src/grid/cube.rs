@@ -0,0 +1,219 @@
+use crate::grid::{Direction, Location, Map};
+use std::collections::VecDeque;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CubeError {
+    #[error("Net area {0} is not six equal square faces")]
+    InvalidArea(usize),
+    #[error("Expected 6 faces of size {size} found {found}")]
+    FaceCount { size: usize, found: usize },
+    #[error("Faces are not connected or do not fold into a cube")]
+    NotCube,
+}
+
+type Vec3 = [i64; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn dot(a: Vec3, b: Vec3) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vec3, k: i64) -> Vec3 {
+    [a[0] * k, a[1] * k, a[2] * k]
+}
+
+#[derive(Debug, Clone)]
+struct Face {
+    origin: Location,
+    normal: Vec3,
+    east: Vec3,
+    south: Vec3,
+}
+
+impl Face {
+    fn towards(&self, direction: &Direction) -> Vec3 {
+        match direction {
+            Direction::North => neg(self.south),
+            Direction::East => self.east,
+            Direction::South => self.south,
+            Direction::West => neg(self.east),
+        }
+    }
+
+    fn fold(&self, direction: &Direction, origin: Location) -> Face {
+        let (normal, east, south) = match direction {
+            Direction::North => (neg(self.south), self.east, self.normal),
+            Direction::East => (self.east, neg(self.normal), self.south),
+            Direction::South => (self.south, self.east, neg(self.normal)),
+            Direction::West => (neg(self.east), self.normal, self.south),
+        };
+        Face {
+            origin,
+            normal,
+            east,
+            south,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CubeNet {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl CubeNet {
+    pub fn new<T, F>(map: &Map<T>, is_blank: F) -> Result<Self, CubeError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let filled = |row: usize, col: usize| {
+            map.0
+                .get(row)
+                .and_then(|r| r.get(col))
+                .is_some_and(|v| !is_blank(v))
+        };
+
+        let area = map.iter().flatten().filter(|(_, v)| !is_blank(v)).count();
+        let size = (1..=area)
+            .take_while(|s| s * s * 6 <= area)
+            .last()
+            .filter(|s| s * s * 6 == area)
+            .ok_or(CubeError::InvalidArea(area))?;
+
+        let rows = map.0.len();
+        let cols = map.0.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut origins = Vec::new();
+        for row in (0..rows).step_by(size) {
+            for col in (0..cols).step_by(size) {
+                if filled(row, col) {
+                    origins.push(Location(row, col));
+                }
+            }
+        }
+        if origins.len() != 6 {
+            return Err(CubeError::FaceCount {
+                size,
+                found: origins.len(),
+            });
+        }
+
+        let mut placed: Vec<Option<Face>> = vec![None; 6];
+        placed[0] = Some(Face {
+            origin: origins[0].clone(),
+            normal: [0, 0, 1],
+            east: [1, 0, 0],
+            south: [0, 1, 0],
+        });
+        let mut queue = VecDeque::from([0]);
+        while let Some(idx) = queue.pop_front() {
+            let face = placed[idx].clone().expect("queued faces are placed");
+            for direction in Direction::all() {
+                let Some(neighbour) = Self::net_neighbour(&face.origin, direction, size) else {
+                    continue;
+                };
+                let Some(n_idx) = origins.iter().position(|o| *o == neighbour) else {
+                    continue;
+                };
+                if placed[n_idx].is_none() {
+                    placed[n_idx] = Some(face.fold(direction, neighbour));
+                    queue.push_back(n_idx);
+                }
+            }
+        }
+
+        let faces: Vec<Face> = placed
+            .into_iter()
+            .collect::<Option<_>>()
+            .ok_or(CubeError::NotCube)?;
+        for (i, a) in faces.iter().enumerate() {
+            if faces[i + 1..].iter().any(|b| b.normal == a.normal) {
+                return Err(CubeError::NotCube);
+            }
+        }
+
+        Ok(CubeNet { size, faces })
+    }
+
+    fn net_neighbour(origin: &Location, direction: &Direction, size: usize) -> Option<Location> {
+        Some(match direction {
+            Direction::North => Location(origin.0.checked_sub(size)?, origin.1),
+            Direction::East => Location(origin.0, origin.1 + size),
+            Direction::South => Location(origin.0 + size, origin.1),
+            Direction::West => Location(origin.0, origin.1.checked_sub(size)?),
+        })
+    }
+
+    pub fn face_size(&self) -> usize {
+        self.size
+    }
+
+    pub fn face_origins(&self) -> impl Iterator<Item = &Location> {
+        self.faces.iter().map(|f| &f.origin)
+    }
+
+    pub fn face_of(&self, location: &Location) -> Option<usize> {
+        let origin = Location(
+            location.0 / self.size * self.size,
+            location.1 / self.size * self.size,
+        );
+        self.faces.iter().position(|f| f.origin == origin)
+    }
+
+    pub fn step(
+        &self,
+        location: &Location,
+        direction: &Direction,
+    ) -> Option<(Location, Direction)> {
+        let face = &self.faces[self.face_of(location)?];
+        let row = location.0 - face.origin.0;
+        let col = location.1 - face.origin.1;
+        let last = self.size - 1;
+
+        let inside = match direction {
+            Direction::North => (row > 0).then(|| Location(location.0 - 1, location.1)),
+            Direction::East => (col < last).then(|| Location(location.0, location.1 + 1)),
+            Direction::South => (row < last).then(|| Location(location.0 + 1, location.1)),
+            Direction::West => (col > 0).then(|| Location(location.0, location.1 - 1)),
+        };
+        if let Some(next) = inside {
+            return Some((next, direction.clone()));
+        }
+
+        // Cell centres in doubled units so a cube of side `size` spans -size..=size
+        let size = self.size as i64;
+        let centre = add(
+            scale(face.normal, size),
+            add(
+                scale(face.east, 2 * col as i64 + 1 - size),
+                scale(face.south, 2 * row as i64 + 1 - size),
+            ),
+        );
+        let out = face.towards(direction);
+        let target = add(centre, add(out, neg(face.normal)));
+
+        let next = self.faces.iter().find(|f| f.normal == out)?;
+        let next_row = (dot(next.south, target) + size - 1) / 2;
+        let next_col = (dot(next.east, target) + size - 1) / 2;
+        let heading = neg(face.normal);
+        let next_direction = Direction::all()
+            .iter()
+            .find(|d| next.towards(d) == heading)?
+            .clone();
+
+        Some((
+            Location(
+                next.origin.0 + next_row as usize,
+                next.origin.1 + next_col as usize,
+            ),
+            next_direction,
+        ))
+    }
+}
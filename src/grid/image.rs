@@ -0,0 +1,150 @@
+use crate::grid::sparse::SparseMap;
+use crate::grid::{Location, Map, UnboundLocation};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+pub type Rgb = [u8; 3];
+
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn from_cells<F>(rows: usize, cols: usize, scale: usize, to_rgb: F) -> Self
+    where
+        F: Fn(usize, usize) -> Rgb,
+    {
+        let width = cols * scale;
+        let height = rows * scale;
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for row in 0..rows {
+            let mut line = Vec::with_capacity(width * 3);
+            for col in 0..cols {
+                let rgb = to_rgb(row, col);
+                for _ in 0..scale {
+                    line.extend_from_slice(&rgb);
+                }
+            }
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn write_ppm<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)
+    }
+
+    pub fn save_ppm<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut out)?;
+        out.flush()
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png<W: Write>(&self, out: W) -> Result<(), png::EncodingError> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()
+    }
+
+    #[cfg(feature = "png")]
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), png::EncodingError> {
+        self.write_png(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T> Map<T> {
+    pub fn to_image<F>(&self, scale: usize, to_rgb: F) -> Image
+    where
+        F: Fn(&T, Location) -> Rgb,
+    {
+        let cols = self.0.iter().map(|r| r.len()).max().unwrap_or(0);
+        Image::from_cells(self.0.len(), cols, scale, |row, col| {
+            match self.0[row].get(col) {
+                Some(value) => to_rgb(value, Location(row, col)),
+                None => [0, 0, 0],
+            }
+        })
+    }
+
+    pub fn save_ppm<P, F>(&self, path: P, scale: usize, to_rgb: F) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T, Location) -> Rgb,
+    {
+        self.to_image(scale, to_rgb).save_ppm(path)
+    }
+
+    #[cfg(feature = "png")]
+    pub fn save_png<P, F>(&self, path: P, scale: usize, to_rgb: F) -> Result<(), png::EncodingError>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T, Location) -> Rgb,
+    {
+        self.to_image(scale, to_rgb).save_png(path)
+    }
+}
+
+impl<T> SparseMap<T> {
+    pub fn to_image<F>(&self, scale: usize, to_rgb: F) -> Option<Image>
+    where
+        F: Fn(&T, UnboundLocation) -> Rgb,
+    {
+        let (min, max) = self.bounds()?;
+        Some(Image::from_cells(
+            (max.0 - min.0 + 1) as usize,
+            (max.1 - min.1 + 1) as usize,
+            scale,
+            |row, col| {
+                let loc = UnboundLocation(min.0 + row as i64, min.1 + col as i64);
+                to_rgb(self.get(&loc), loc)
+            },
+        ))
+    }
+}
+
+pub fn points_to_image<'a, I>(points: I, scale: usize, on: Rgb, off: Rgb) -> Option<Image>
+where
+    I: IntoIterator<Item = &'a UnboundLocation>,
+{
+    let points: HashSet<&UnboundLocation> = points.into_iter().collect();
+    let min_row = points.iter().map(|p| p.0).min()?;
+    let max_row = points.iter().map(|p| p.0).max()?;
+    let min_col = points.iter().map(|p| p.1).min()?;
+    let max_col = points.iter().map(|p| p.1).max()?;
+    Some(Image::from_cells(
+        (max_row - min_row + 1) as usize,
+        (max_col - min_col + 1) as usize,
+        scale,
+        |row, col| {
+            let loc = UnboundLocation(min_row + row as i64, min_col + col as i64);
+            if points.contains(&loc) { on } else { off }
+        },
+    ))
+}
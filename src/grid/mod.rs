@@ -1,6 +1,25 @@
+pub mod automaton;
+pub mod beam;
+pub mod bits;
+pub mod cube;
+pub mod image;
+pub mod ocr;
+pub mod pattern;
+pub mod pipes;
+pub mod ray;
+pub mod reflect;
+pub mod render;
+pub mod slide;
+pub mod sparse;
+pub mod view;
+#[cfg(feature = "viz")]
+pub mod viz;
+
 use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Axis {
     Rows,
     Cols,
@@ -92,6 +111,99 @@ impl Direction {
     }
 }
 
+#[derive(Debug, Clone, Copy, Ord, Eq, PartialEq, PartialOrd, Hash, Default)]
+pub struct Vec2(pub i64, pub i64);
+
+impl Vec2 {
+    pub fn manhattan_len(&self) -> u64 {
+        self.0.unsigned_abs() + self.1.unsigned_abs()
+    }
+
+    pub fn left(&self) -> Self {
+        Vec2(-self.1, self.0)
+    }
+
+    pub fn right(&self) -> Self {
+        Vec2(self.1, -self.0)
+    }
+
+    pub fn rotate(&self, turn: &Direction) -> Self {
+        match turn {
+            Direction::North => *self,
+            Direction::East => self.right(),
+            Direction::South => -*self,
+            Direction::West => self.left(),
+        }
+    }
+
+    pub fn to_direction(&self) -> Option<Direction> {
+        UnboundLocation(0, 0).direction_between(&UnboundLocation(self.0, self.1))
+    }
+}
+
+impl From<&Direction> for Vec2 {
+    fn from(direction: &Direction) -> Self {
+        match direction {
+            Direction::North => Vec2(-1, 0),
+            Direction::East => Vec2(0, 1),
+            Direction::South => Vec2(1, 0),
+            Direction::West => Vec2(0, -1),
+        }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        (&direction).into()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Vec2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Vec2(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2(-self.0, -self.1)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
 #[derive(Debug, Clone, Ord, Eq, PartialEq, PartialOrd, Hash)]
 pub struct UnboundLocation(pub i64, pub i64);
 
@@ -135,6 +247,112 @@ impl UnboundLocation {
     }
 }
 
+impl Add<Vec2> for UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        UnboundLocation(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Add<Vec2> for &UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        UnboundLocation(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub<Vec2> for UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        UnboundLocation(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Sub<Vec2> for &UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        UnboundLocation(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Sub for UnboundLocation {
+    type Output = Vec2;
+
+    fn sub(self, rhs: UnboundLocation) -> Self::Output {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Sub for &UnboundLocation {
+    type Output = Vec2;
+
+    fn sub(self, rhs: &UnboundLocation) -> Self::Output {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<i64> for UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        UnboundLocation(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Neg for UnboundLocation {
+    type Output = UnboundLocation;
+
+    fn neg(self) -> Self::Output {
+        UnboundLocation(-self.0, -self.1)
+    }
+}
+
+impl AddAssign<Vec2> for UnboundLocation {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+impl SubAssign<Vec2> for UnboundLocation {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
+impl From<&Location> for UnboundLocation {
+    fn from(location: &Location) -> Self {
+        UnboundLocation(location.0 as i64, location.1 as i64)
+    }
+}
+
+impl From<Location> for UnboundLocation {
+    fn from(location: Location) -> Self {
+        (&location).into()
+    }
+}
+
+impl TryFrom<&UnboundLocation> for Location {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(location: &UnboundLocation) -> Result<Self, Self::Error> {
+        Ok(Location(location.0.try_into()?, location.1.try_into()?))
+    }
+}
+
+impl TryFrom<UnboundLocation> for Location {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(location: UnboundLocation) -> Result<Self, Self::Error> {
+        (&location).try_into()
+    }
+}
+
 #[derive(Debug, Clone, Ord, Eq, PartialEq, PartialOrd, Hash)]
 pub struct Location(pub usize, pub usize);
 
@@ -156,7 +374,7 @@ impl Location {
 
 pub type Grid<T> = Vec<Vec<T>>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Map<T>(pub Grid<T>);
 
 impl<T> Map<T> {
@@ -206,6 +424,17 @@ impl<T> Map<T> {
         &mut self.0[location.0][location.1]
     }
 
+    pub fn swap(&mut self, a: &Location, b: &Location) {
+        match a.0.cmp(&b.0) {
+            Ordering::Equal => self.0[a.0].swap(a.1, b.1),
+            Ordering::Less => {
+                let (top, bottom) = self.0.split_at_mut(b.0);
+                std::mem::swap(&mut top[a.0][a.1], &mut bottom[0][b.1]);
+            }
+            Ordering::Greater => self.swap(b, a),
+        }
+    }
+
     pub fn get_location(&self, x: usize, y: usize) -> MapResult<Location> {
         match self.0.get(x) {
             Some(row) => match row.get(y) {
@@ -245,6 +474,27 @@ impl<T> Map<T> {
         }
     }
 
+    pub fn go_direction_wrapping(
+        &self,
+        current: &Location,
+        direction: &Direction,
+    ) -> Option<Location> {
+        self.wrap_location(&(UnboundLocation::from(current) + Vec2::from(direction)))
+    }
+
+    pub fn wrap_location(&self, location: &UnboundLocation) -> Option<Location> {
+        let cols = self.width().filter(|w| *w > 0)? as i64;
+        let rows = self.0.len() as i64;
+        Some(Location(
+            location.0.rem_euclid(rows) as usize,
+            location.1.rem_euclid(cols) as usize,
+        ))
+    }
+
+    pub fn teleport(&self, start: &Location, velocity: Vec2, steps: i64) -> Option<Location> {
+        self.wrap_location(&(UnboundLocation::from(start) + velocity * steps))
+    }
+
     pub fn iter_direction(
         &'_ self,
         start: Location,
@@ -256,7 +506,47 @@ impl<T> Map<T> {
             current: start,
             x_direction,
             y_direction,
+            wrapping: false,
+        }
+    }
+
+    pub fn iter_direction_wrapping(
+        &'_ self,
+        start: Location,
+        x_direction: Option<Direction>,
+        y_direction: Option<Direction>,
+    ) -> DirectionIterator<'_, T> {
+        DirectionIterator {
+            map: self,
+            current: start,
+            x_direction,
+            y_direction,
+            wrapping: true,
+        }
+    }
+
+    pub fn flood_fill<F>(&self, start: &Location, can_enter: F) -> CountingMap
+    where
+        F: Fn(&Location, &T) -> bool,
+    {
+        let mut seen = CountingMap::from(self);
+        if !can_enter(start, self.get(start)) {
+            return seen;
         }
+        seen.mark(start);
+        let mut queue = VecDeque::from([start.clone()]);
+        while let Some(current) = queue.pop_front() {
+            for direction in Direction::all() {
+                if let Some(next) = self.go_direction(&current, direction)
+                    && !seen.get(&next)
+                    && can_enter(&next, self.get(&next))
+                {
+                    seen.mark(&next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        seen
     }
 
     pub fn get_edges(&self) -> Vec<(Location, Direction)> {
@@ -290,13 +580,7 @@ impl<T> Map<T> {
     where
         F: Fn(&T, Location) -> char,
     {
-        for row in self.iter() {
-            for (loc, col) in row {
-                let out_char = to_char(col, loc);
-                print!("{}", out_char);
-            }
-            println!();
-        }
+        print!("{}", self.render(to_char));
     }
 
     pub fn transform<N, F>(&self, transform_single: F) -> Map<N>
@@ -313,6 +597,65 @@ impl<T> Map<T> {
         }
         Map(out)
     }
+
+    pub fn transpose(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        let cols = self.width().unwrap_or(0);
+        Map::from_dimensions(cols, self.0.len(), |loc| self.0[loc.1][loc.0].clone())
+    }
+
+    pub fn flip_horizontal(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map(self
+            .0
+            .iter()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect())
+    }
+
+    pub fn flip_vertical(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map(self.0.iter().rev().cloned().collect())
+    }
+
+    pub fn rotate_right(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_left(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        self.transpose().flip_vertical()
+    }
+
+    pub fn orientations(&self) -> Vec<Map<T>>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut out: Vec<Map<T>> = Vec::with_capacity(8);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            let flipped = current.flip_horizontal();
+            let next = current.rotate_right();
+            for candidate in [current, flipped] {
+                if !out.contains(&candidate) {
+                    out.push(candidate);
+                }
+            }
+            current = next;
+        }
+        out
+    }
 }
 
 pub struct MapIterator<'a, T> {
@@ -371,9 +714,20 @@ pub struct DirectionIterator<'a, T> {
     current: Location,
     x_direction: Option<Direction>,
     y_direction: Option<Direction>,
+    wrapping: bool,
     map: &'a Map<T>,
 }
 
+impl<T> DirectionIterator<'_, T> {
+    fn step(&self, current: &Location, direction: &Direction) -> Option<Location> {
+        if self.wrapping {
+            self.map.go_direction_wrapping(current, direction)
+        } else {
+            self.map.go_direction(current, direction)
+        }
+    }
+}
+
 impl<'a, T> Iterator for DirectionIterator<'a, T> {
     type Item = (Location, &'a T);
 
@@ -383,10 +737,10 @@ impl<'a, T> Iterator for DirectionIterator<'a, T> {
         }
         let mut new_location = self.current.clone();
         if let Some(direction) = &self.x_direction {
-            new_location = self.map.go_direction(&new_location, direction)?;
+            new_location = self.step(&new_location, direction)?;
         }
         if let Some(direction) = &self.y_direction {
-            new_location = self.map.go_direction(&new_location, direction)?;
+            new_location = self.step(&new_location, direction)?;
         }
         self.current = new_location.clone();
         Some((new_location.clone(), self.map.get(&new_location)))
@@ -414,6 +768,10 @@ impl CountingMap {
     pub fn unique(&self) -> usize {
         self.1
     }
+
+    pub fn marked(&self) -> impl Iterator<Item = Location> + '_ {
+        self.0.iter().flatten().filter(|(_, v)| **v).map(|(l, _)| l)
+    }
 }
 
 impl<T> From<&Map<T>> for CountingMap {
@@ -0,0 +1,246 @@
+use crate::grid::{CountingMap, Map, UnboundLocation};
+use std::collections::HashSet;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OcrError {
+    #[error("No lit cells to decode")]
+    Empty,
+    #[error("No glyph alphabet with height {0}")]
+    UnsupportedHeight(usize),
+    #[error("Unknown glyphs decoding {partial:?}:\n{}", glyphs.join("\n\n"))]
+    UnknownGlyphs {
+        partial: String,
+        glyphs: Vec<String>,
+    },
+}
+
+const ALPHABET_6: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const ALPHABET_10: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+type Glyph = Vec<Vec<bool>>;
+
+fn parse_glyph(glyph: &str) -> Glyph {
+    trim_columns(
+        glyph
+            .lines()
+            .map(|l| l.chars().map(|c| c == '#').collect())
+            .collect(),
+    )
+}
+
+fn glyph_string(glyph: &Glyph) -> String {
+    glyph
+        .iter()
+        .map(|row| row.iter().map(|v| if *v { '#' } else { '.' }).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn column_blank(rows: &Glyph, col: usize) -> bool {
+    rows.iter()
+        .all(|row| !row.get(col).copied().unwrap_or(false))
+}
+
+fn trim_columns(rows: Glyph) -> Glyph {
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let Some(start) = (0..width).find(|c| !column_blank(&rows, *c)) else {
+        return rows.iter().map(|_| Vec::new()).collect();
+    };
+    let end = (0..width)
+        .rfind(|c| !column_blank(&rows, *c))
+        .unwrap_or(start);
+    rows.into_iter()
+        .map(|row| {
+            (start..=end)
+                .map(|c| row.get(c).copied().unwrap_or(false))
+                .collect()
+        })
+        .collect()
+}
+
+fn trim_rows(rows: Glyph) -> Glyph {
+    let lit = |row: &Vec<bool>| row.iter().any(|v| *v);
+    let Some(start) = rows.iter().position(lit) else {
+        return Vec::new();
+    };
+    let end = rows.iter().rposition(lit).unwrap_or(start);
+    rows[start..=end].to_vec()
+}
+
+fn decode(lit: Glyph) -> Result<String, OcrError> {
+    let rows = trim_columns(trim_rows(lit));
+    if rows.is_empty() {
+        return Err(OcrError::Empty);
+    }
+    let alphabet = match rows.len() {
+        6 => ALPHABET_6,
+        10 => ALPHABET_10,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+    let mut known: Vec<(char, Glyph)> = alphabet
+        .iter()
+        .map(|(c, glyph)| (*c, parse_glyph(glyph)))
+        .collect();
+    known.sort_by_key(|(_, glyph)| std::cmp::Reverse(glyph[0].len()));
+
+    let width = rows[0].len();
+    let mut out = String::new();
+    let mut unknown = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if column_blank(&rows, col) {
+            col += 1;
+            continue;
+        }
+        let matched = known.iter().find(|(_, k)| {
+            let glyph_width = k[0].len();
+            col + glyph_width <= width
+                && rows
+                    .iter()
+                    .zip(k.iter())
+                    .all(|(row, k_row)| row[col..col + glyph_width] == k_row[..])
+        });
+        if let Some((c, k)) = matched {
+            out.push(*c);
+            col += k[0].len();
+            continue;
+        }
+        let end = (col..width)
+            .find(|c| column_blank(&rows, *c))
+            .unwrap_or(width);
+        let glyph: Glyph = rows.iter().map(|row| row[col..end].to_vec()).collect();
+        out.push('?');
+        unknown.push(glyph_string(&glyph));
+        col = end;
+    }
+
+    if unknown.is_empty() {
+        Ok(out)
+    } else {
+        Err(OcrError::UnknownGlyphs {
+            partial: out,
+            glyphs: unknown,
+        })
+    }
+}
+
+pub fn ocr_points<'a, I>(points: I) -> Result<String, OcrError>
+where
+    I: IntoIterator<Item = &'a UnboundLocation>,
+{
+    let points: HashSet<&UnboundLocation> = points.into_iter().collect();
+    let min_row = points.iter().map(|p| p.0).min().ok_or(OcrError::Empty)?;
+    let max_row = points.iter().map(|p| p.0).max().ok_or(OcrError::Empty)?;
+    let min_col = points.iter().map(|p| p.1).min().ok_or(OcrError::Empty)?;
+    let max_col = points.iter().map(|p| p.1).max().ok_or(OcrError::Empty)?;
+    decode(
+        (min_row..=max_row)
+            .map(|row| {
+                (min_col..=max_col)
+                    .map(|col| points.contains(&UnboundLocation(row, col)))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+impl<T> Map<T> {
+    pub fn ocr<F>(&self, is_lit: F) -> Result<String, OcrError>
+    where
+        F: Fn(&T) -> bool,
+    {
+        decode(
+            self.0
+                .iter()
+                .map(|row| row.iter().map(&is_lit).collect())
+                .collect(),
+        )
+    }
+}
+
+impl CountingMap {
+    pub fn ocr(&self) -> Result<String, OcrError> {
+        self.0.ocr(|v| *v)
+    }
+}
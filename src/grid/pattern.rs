@@ -0,0 +1,66 @@
+use crate::grid::{CountingMap, Location, Map};
+
+impl<T: PartialEq> Map<T> {
+    pub fn matches_at(&self, origin: &Location, pattern: &Map<Option<T>>) -> bool {
+        pattern.iter().flatten().all(|(loc, expected)| {
+            let Some(expected) = expected else {
+                return true;
+            };
+            self.0
+                .get(origin.0 + loc.0)
+                .and_then(|row| row.get(origin.1 + loc.1))
+                .is_some_and(|v| v == expected)
+        })
+    }
+
+    pub fn find_pattern(&self, pattern: &Map<Option<T>>) -> Vec<Location> {
+        let rows = pattern.0.len();
+        let cols = pattern.width().unwrap_or(0);
+        let mut out = Vec::new();
+        for (row_num, row) in self.0.iter().enumerate() {
+            if row_num + rows > self.0.len() {
+                break;
+            }
+            for col_num in 0..(row.len() + 1).saturating_sub(cols) {
+                let origin = Location(row_num, col_num);
+                if self.matches_at(&origin, pattern) {
+                    out.push(origin);
+                }
+            }
+        }
+        out
+    }
+
+    pub fn find_patterns(&self, patterns: &[Map<Option<T>>]) -> Vec<(Location, usize)> {
+        patterns
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, pattern)| {
+                self.find_pattern(pattern)
+                    .into_iter()
+                    .map(move |loc| (loc, idx))
+            })
+            .collect()
+    }
+}
+
+impl<T> Map<Option<T>> {
+    pub fn pattern_cells<'a>(
+        &'a self,
+        origin: &'a Location,
+    ) -> impl Iterator<Item = Location> + 'a {
+        self.iter()
+            .flatten()
+            .filter(|(_, v)| v.is_some())
+            .map(|(loc, _)| Location(origin.0 + loc.0, origin.1 + loc.1))
+    }
+}
+
+impl CountingMap {
+    pub fn mark_pattern<T>(&mut self, origin: &Location, pattern: &Map<Option<T>>) -> usize {
+        pattern
+            .pattern_cells(origin)
+            .filter(|loc| self.mark(loc))
+            .count()
+    }
+}
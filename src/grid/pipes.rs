@@ -0,0 +1,104 @@
+use crate::grid::{CountingMap, Direction, Location, Map};
+
+pub trait Connects {
+    fn connects(&self, direction: &Direction) -> bool;
+}
+
+impl Connects for char {
+    fn connects(&self, direction: &Direction) -> bool {
+        matches!(
+            (self, direction),
+            ('|', Direction::North | Direction::South)
+                | ('-', Direction::East | Direction::West)
+                | ('L', Direction::North | Direction::East)
+                | ('J', Direction::North | Direction::West)
+                | ('7', Direction::South | Direction::West)
+                | ('F', Direction::South | Direction::East)
+        )
+    }
+}
+
+fn loop_connections(path: &[Location]) -> Vec<Direction> {
+    match (path.get(1), path.last()) {
+        (Some(next), Some(last)) => [next, last]
+            .into_iter()
+            .filter_map(|neighbour| path[0].direction_between(neighbour))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl<T: Connects> Map<T> {
+    fn pointing_into(&self, location: &Location) -> Vec<Direction> {
+        Direction::all()
+            .iter()
+            .filter(|d| {
+                self.go_direction(location, d)
+                    .is_some_and(|next| self.get(&next).connects(&d.invert()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn infer_connections(&self, location: &Location) -> Vec<Direction> {
+        self.find_loop(location)
+            .map(|path| loop_connections(&path))
+            .unwrap_or_default()
+    }
+
+    fn walk_loop(&self, start: &Location, mut heading: Direction) -> Option<Vec<Location>> {
+        let mut current = start.clone();
+        let mut path = vec![start.clone()];
+        loop {
+            current = self.go_direction(&current, &heading)?;
+            if current == *start {
+                return (path.len() > 1).then_some(path);
+            }
+            let came_from = heading.invert();
+            let value = self.get(&current);
+            if !value.connects(&came_from) {
+                return None;
+            }
+            heading = Direction::all()
+                .iter()
+                .find(|d| **d != came_from && value.connects(d))?
+                .clone();
+            path.push(current.clone());
+        }
+    }
+
+    pub fn find_loop(&self, start: &Location) -> Option<Vec<Location>> {
+        self.pointing_into(start)
+            .into_iter()
+            .find_map(|heading| self.walk_loop(start, heading))
+    }
+
+    pub fn enclosed(&self, path: &[Location]) -> CountingMap {
+        let mut on_loop = CountingMap::from(self);
+        for loc in path {
+            on_loop.mark(loc);
+        }
+        let start = path.first();
+        let start_north = loop_connections(path).contains(&Direction::North);
+
+        let mut inside = CountingMap::from(self);
+        for row in self.iter() {
+            let mut crossings = 0;
+            for (loc, value) in row {
+                if on_loop.get(&loc) {
+                    let north = if Some(&loc) == start {
+                        start_north
+                    } else {
+                        value.connects(&Direction::North)
+                    };
+                    if north {
+                        crossings += 1;
+                    }
+                } else if crossings % 2 == 1 {
+                    inside.mark(&loc);
+                }
+            }
+        }
+        inside
+    }
+}
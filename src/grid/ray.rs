@@ -0,0 +1,146 @@
+use crate::grid::{Location, Map, UnboundLocation, Vec2};
+use crate::math::gcd;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+impl Vec2 {
+    pub fn reduced(&self) -> Vec2 {
+        match gcd(self.0, self.1) {
+            0 => *self,
+            divisor => Vec2(self.0 / divisor, self.1 / divisor),
+        }
+    }
+
+    fn half(&self) -> u8 {
+        if self.1 > 0 || (self.1 == 0 && self.0 < 0) {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn clockwise_cmp(&self, other: &Vec2) -> Ordering {
+        self.half()
+            .cmp(&other.half())
+            .then_with(|| (self.0 * other.1).cmp(&(self.1 * other.0)))
+    }
+}
+
+pub struct RayIterator<'a, T> {
+    current: UnboundLocation,
+    step: Vec2,
+    map: &'a Map<T>,
+}
+
+impl<'a, T> Iterator for RayIterator<'a, T> {
+    type Item = (Location, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == Vec2(0, 0) {
+            return None;
+        }
+        let next = &self.current + self.step;
+        let location = next.clone().to_bounded(self.map).ok()?;
+        self.current = next;
+        Some((location.clone(), self.map.get(&location)))
+    }
+}
+
+impl<T> Map<T> {
+    pub fn iter_ray(&self, start: &Location, slope: Vec2) -> RayIterator<'_, T> {
+        RayIterator {
+            current: start.into(),
+            step: slope.reduced(),
+            map: self,
+        }
+    }
+
+    pub fn first_hit<F>(&self, start: &Location, slope: Vec2, is_hit: F) -> Option<(Location, &T)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter_ray(start, slope).find(|(_, v)| is_hit(v))
+    }
+
+    pub fn visible_from<F>(&self, start: &Location, is_target: F) -> Vec<Location>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let origin = UnboundLocation::from(start);
+        let mut nearest: HashMap<Vec2, (u64, Location)> = HashMap::new();
+        for (loc, value) in self.iter().flatten() {
+            if loc == *start || !is_target(value) {
+                continue;
+            }
+            let offset = &UnboundLocation::from(&loc) - &origin;
+            let dist = offset.manhattan_len();
+            let entry = nearest
+                .entry(offset.reduced())
+                .or_insert((dist, loc.clone()));
+            if dist < entry.0 {
+                *entry = (dist, loc);
+            }
+        }
+        nearest.into_values().map(|(_, loc)| loc).collect()
+    }
+
+    pub fn count_visible<F>(&self, start: &Location, is_target: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.visible_from(start, is_target).len()
+    }
+}
+
+pub fn laser_order<I>(origin: &UnboundLocation, targets: I) -> Vec<UnboundLocation>
+where
+    I: IntoIterator<Item = UnboundLocation>,
+{
+    let mut by_angle: BTreeMap<AngleKey, Vec<(u64, UnboundLocation)>> = BTreeMap::new();
+    for target in targets {
+        if target == *origin {
+            continue;
+        }
+        let offset = &target - origin;
+        by_angle
+            .entry(AngleKey(offset.reduced()))
+            .or_default()
+            .push((offset.manhattan_len(), target));
+    }
+
+    let mut rings: Vec<std::vec::IntoIter<(u64, UnboundLocation)>> = by_angle
+        .into_values()
+        .map(|mut targets| {
+            targets.sort();
+            targets.into_iter()
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    loop {
+        let before = out.len();
+        for ring in rings.iter_mut() {
+            if let Some((_, target)) = ring.next() {
+                out.push(target);
+            }
+        }
+        if out.len() == before {
+            return out;
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct AngleKey(Vec2);
+
+impl Ord for AngleKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.clockwise_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for AngleKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
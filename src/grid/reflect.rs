@@ -0,0 +1,77 @@
+use crate::grid::{Axis, Map};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub position: usize,
+    pub mismatches: usize,
+}
+
+fn hash_line<'a, T: Hash + 'a>(line: impl Iterator<Item = &'a T>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for value in line {
+        value.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn find_reflections<F>(
+    axis: Axis,
+    hashes: &[u64],
+    max_mismatches: usize,
+    mismatch: F,
+) -> Vec<Reflection>
+where
+    F: Fn(usize, usize) -> usize,
+{
+    let mut out = Vec::new();
+    for position in 1..hashes.len() {
+        let mut mismatches = 0;
+        for (a, b) in (0..position).rev().zip(position..hashes.len()) {
+            if hashes[a] != hashes[b] {
+                mismatches += mismatch(a, b);
+                if mismatches > max_mismatches {
+                    break;
+                }
+            }
+        }
+        if mismatches <= max_mismatches {
+            out.push(Reflection {
+                axis,
+                position,
+                mismatches,
+            });
+        }
+    }
+    out
+}
+
+impl<T: Eq + Hash> Map<T> {
+    pub fn row_reflections(&self, max_mismatches: usize) -> Vec<Reflection> {
+        let hashes: Vec<u64> = self.0.iter().map(|row| hash_line(row.iter())).collect();
+        find_reflections(Axis::Rows, &hashes, max_mismatches, |a, b| {
+            self.0[a]
+                .iter()
+                .zip(self.0[b].iter())
+                .filter(|(x, y)| x != y)
+                .count()
+        })
+    }
+
+    pub fn col_reflections(&self, max_mismatches: usize) -> Vec<Reflection> {
+        let cols = self.width().unwrap_or(0);
+        let hashes: Vec<u64> = (0..cols)
+            .map(|c| hash_line(self.0.iter().map(|row| &row[c])))
+            .collect();
+        find_reflections(Axis::Cols, &hashes, max_mismatches, |a, b| {
+            self.0.iter().filter(|row| row[a] != row[b]).count()
+        })
+    }
+
+    pub fn reflections(&self, max_mismatches: usize) -> Vec<Reflection> {
+        let mut out = self.row_reflections(max_mismatches);
+        out.extend(self.col_reflections(max_mismatches));
+        out
+    }
+}
@@ -0,0 +1,161 @@
+use crate::grid::{CountingMap, Direction, Location, Map};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct MapRender<'a, T, F> {
+    map: &'a Map<T>,
+    to_char: F,
+    rulers: bool,
+    overlay: HashMap<Location, char>,
+}
+
+impl<'a, T, F> MapRender<'a, T, F>
+where
+    F: Fn(&T, Location) -> char,
+{
+    pub fn new(map: &'a Map<T>, to_char: F) -> Self {
+        Self {
+            map,
+            to_char,
+            rulers: false,
+            overlay: HashMap::new(),
+        }
+    }
+
+    pub fn rulers(mut self, enabled: bool) -> Self {
+        self.rulers = enabled;
+        self
+    }
+
+    pub fn highlight<'l, I>(mut self, locations: I, c: char) -> Self
+    where
+        I: IntoIterator<Item = &'l Location>,
+    {
+        for loc in locations {
+            self.overlay.insert(loc.clone(), c);
+        }
+        self
+    }
+
+    pub fn path(mut self, path: &[Location]) -> Self {
+        for step in path.windows(2) {
+            let c = match step[0].direction_between(&step[1]) {
+                Some(direction) => direction_char(&direction),
+                None => '*',
+            };
+            self.overlay.insert(step[0].clone(), c);
+        }
+        if let Some(last) = path.last() {
+            self.overlay.insert(last.clone(), '*');
+        }
+        self
+    }
+
+    pub fn write_io<W: std::io::Write>(&self, out: &mut W) -> std::io::Result<()> {
+        write!(out, "{}", self)
+    }
+
+    fn cell_char(&self, value: &T, location: Location) -> char {
+        match self.overlay.get(&location) {
+            Some(c) => *c,
+            None => (self.to_char)(value, location),
+        }
+    }
+}
+
+fn direction_char(direction: &Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().unwrap_or(0) as usize + 1
+}
+
+impl<T, F> fmt::Display for MapRender<'_, T, F>
+where
+    F: Fn(&T, Location) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row_width = digits(self.map.0.len().saturating_sub(1));
+        if self.rulers {
+            let cols = self.map.0.iter().map(|r| r.len()).max().unwrap_or(0);
+            let col_width = digits(cols.saturating_sub(1));
+            for place in (0..col_width).rev() {
+                write!(f, "{:row_width$} ", "")?;
+                for col in 0..cols {
+                    let power = 10usize.pow(place as u32);
+                    if place == 0 || col >= power {
+                        write!(f, "{}", col / power % 10)?;
+                    } else {
+                        write!(f, " ")?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for row in self.map.iter() {
+            if self.rulers {
+                write!(f, "{:>row_width$} ", row.row_num())?;
+            }
+            for (loc, value) in row {
+                write!(f, "{}", self.cell_char(value, loc))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Map<T> {
+    pub fn render<F>(&self, to_char: F) -> MapRender<'_, T, F>
+    where
+        F: Fn(&T, Location) -> char,
+    {
+        MapRender::new(self, to_char)
+    }
+
+    pub fn render_to<W, F>(&self, out: &mut W, to_char: F) -> fmt::Result
+    where
+        W: fmt::Write,
+        F: Fn(&T, Location) -> char,
+    {
+        write!(out, "{}", self.render(to_char))
+    }
+
+    pub fn render_string<F>(&self, to_char: F) -> String
+    where
+        F: Fn(&T, Location) -> char,
+    {
+        self.render(to_char).to_string()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Map<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter() {
+            for (_, value) in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl CountingMap {
+    pub fn render(&self) -> MapRender<'_, bool, fn(&bool, Location) -> char> {
+        self.0.render(|v, _| if *v { '#' } else { '.' })
+    }
+}
+
+impl fmt::Display for CountingMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
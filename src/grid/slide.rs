@@ -0,0 +1,141 @@
+use crate::grid::{Direction, Location, Map};
+use std::collections::{HashSet, VecDeque};
+
+pub enum PushCell {
+    Empty,
+    Wall,
+    Linked(Vec<Location>),
+}
+
+impl<T> Map<T> {
+    fn lines_towards(&self, direction: &Direction) -> Vec<Vec<Location>> {
+        let rows = self.0.len();
+        let cols = self.width().unwrap_or(0);
+        match direction {
+            Direction::North => (0..cols)
+                .map(|c| (0..rows).map(|r| Location(r, c)).collect())
+                .collect(),
+            Direction::South => (0..cols)
+                .map(|c| (0..rows).rev().map(|r| Location(r, c)).collect())
+                .collect(),
+            Direction::East => (0..rows)
+                .map(|r| (0..cols).rev().map(|c| Location(r, c)).collect())
+                .collect(),
+            Direction::West => (0..rows)
+                .map(|r| (0..cols).map(|c| Location(r, c)).collect())
+                .collect(),
+        }
+    }
+
+    pub fn slide<M, B>(&mut self, direction: &Direction, is_movable: M, is_blocking: B) -> usize
+    where
+        M: Fn(&T) -> bool,
+        B: Fn(&T) -> bool,
+    {
+        let mut moved = 0;
+        for line in self.lines_towards(direction) {
+            let mut target = 0;
+            for idx in 0..line.len() {
+                let value = self.get(&line[idx]);
+                if is_blocking(value) {
+                    target = idx + 1;
+                } else if is_movable(value) {
+                    if target != idx {
+                        self.swap(&line[target], &line[idx]);
+                        moved += 1;
+                    }
+                    target += 1;
+                }
+            }
+        }
+        moved
+    }
+
+    pub fn push<M, B>(
+        &mut self,
+        start: &Location,
+        direction: &Direction,
+        is_movable: M,
+        is_blocking: B,
+    ) -> bool
+    where
+        M: Fn(&T) -> bool,
+        B: Fn(&T) -> bool,
+    {
+        let mut chain = vec![start.clone()];
+        loop {
+            let last = chain.last().expect("chain starts non-empty");
+            let Some(next) = self.go_direction(last, direction) else {
+                return false;
+            };
+            let value = self.get(&next);
+            if is_blocking(value) {
+                return false;
+            }
+            let movable = is_movable(value);
+            chain.push(next);
+            if !movable {
+                break;
+            }
+        }
+        for pair in chain.windows(2).rev() {
+            self.swap(&pair[0], &pair[1]);
+        }
+        true
+    }
+
+    pub fn plan_push_group<C>(
+        &self,
+        start: &Location,
+        direction: &Direction,
+        classify: C,
+    ) -> Option<Vec<Location>>
+    where
+        C: Fn(&Location, &T) -> PushCell,
+    {
+        let mut moving = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([start.clone()]);
+        while let Some(current) = queue.pop_front() {
+            let next = self.go_direction(&current, direction)?;
+            if moving.contains(&next) {
+                continue;
+            }
+            match classify(&next, self.get(&next)) {
+                PushCell::Empty => {}
+                PushCell::Wall => return None,
+                PushCell::Linked(cells) => {
+                    for cell in cells {
+                        if moving.insert(cell.clone()) {
+                            queue.push_back(cell);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut out: Vec<Location> = moving.into_iter().collect();
+        match direction {
+            Direction::North => out.sort_by_key(|l| l.0),
+            Direction::South => out.sort_by_key(|l| std::cmp::Reverse(l.0)),
+            Direction::East => out.sort_by_key(|l| std::cmp::Reverse(l.1)),
+            Direction::West => out.sort_by_key(|l| l.1),
+        }
+        Some(out)
+    }
+
+    pub fn push_group<C>(&mut self, start: &Location, direction: &Direction, classify: C) -> bool
+    where
+        C: Fn(&Location, &T) -> PushCell,
+    {
+        let Some(moving) = self.plan_push_group(start, direction, classify) else {
+            return false;
+        };
+        for loc in moving {
+            let next = self
+                .go_direction(&loc, direction)
+                .expect("planned moves stay in bounds");
+            self.swap(&loc, &next);
+        }
+        true
+    }
+}
@@ -0,0 +1,157 @@
+use crate::grid::{Direction, Location, Map, UnboundLocation, Vec2};
+use std::collections::HashMap;
+
+const DIAGONALS: [Vec2; 4] = [Vec2(-1, -1), Vec2(-1, 1), Vec2(1, 1), Vec2(1, -1)];
+
+#[derive(Debug, Clone)]
+pub struct SparseMap<T> {
+    cells: HashMap<UnboundLocation, T>,
+    default: T,
+}
+
+impl<T> SparseMap<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn from_map<F>(map: &Map<T>, default: T, keep: F) -> Self
+    where
+        T: Clone,
+        F: Fn(&Location, &T) -> bool,
+    {
+        let mut out = Self::new(default);
+        for (loc, value) in map.iter().flatten() {
+            if keep(&loc, value) {
+                out.set(loc.into(), value.clone());
+            }
+        }
+        out
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    pub fn get(&self, location: &UnboundLocation) -> &T {
+        self.cells.get(location).unwrap_or(&self.default)
+    }
+
+    pub fn get_mut(&mut self, location: &UnboundLocation) -> &mut T
+    where
+        T: Clone,
+    {
+        self.cells
+            .entry(location.clone())
+            .or_insert_with(|| self.default.clone())
+    }
+
+    pub fn set(&mut self, location: UnboundLocation, value: T) -> Option<T> {
+        self.cells.insert(location, value)
+    }
+
+    pub fn remove(&mut self, location: &UnboundLocation) -> Option<T> {
+        self.cells.remove(location)
+    }
+
+    pub fn contains(&self, location: &UnboundLocation) -> bool {
+        self.cells.contains_key(location)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&UnboundLocation, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&UnboundLocation, &mut T) -> bool,
+    {
+        self.cells.retain(keep)
+    }
+
+    pub fn bounds(&self) -> Option<(UnboundLocation, UnboundLocation)> {
+        let mut locations = self.cells.keys();
+        let first = locations.next()?;
+        let mut min = first.clone();
+        let mut max = first.clone();
+        for loc in locations {
+            min = UnboundLocation(min.0.min(loc.0), min.1.min(loc.1));
+            max = UnboundLocation(max.0.max(loc.0), max.1.max(loc.1));
+        }
+        Some((min, max))
+    }
+
+    pub fn neighbours(
+        &self,
+        location: &UnboundLocation,
+    ) -> impl Iterator<Item = (UnboundLocation, &T)> {
+        Direction::all().iter().map(move |d| {
+            let next = location.go_direction(d, 1);
+            let value = self.get(&next);
+            (next, value)
+        })
+    }
+
+    pub fn neighbours_diagonal(
+        &self,
+        location: &UnboundLocation,
+    ) -> impl Iterator<Item = (UnboundLocation, &T)> {
+        Direction::all()
+            .iter()
+            .map(Vec2::from)
+            .chain(DIAGONALS)
+            .map(move |offset| {
+                let next = location + offset;
+                let value = self.get(&next);
+                (next, value)
+            })
+    }
+
+    pub fn to_map(&self) -> Option<(UnboundLocation, Map<T>)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds()?;
+        let map = Map::from_dimensions(
+            (max.0 - min.0 + 1) as usize,
+            (max.1 - min.1 + 1) as usize,
+            |loc| {
+                self.get(&(UnboundLocation::from(loc) + Vec2(min.0, min.1)))
+                    .clone()
+            },
+        );
+        Some((min, map))
+    }
+
+    pub fn print<F>(&self, to_char: F)
+    where
+        F: Fn(&T, UnboundLocation) -> char,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return;
+        };
+        for row in min.0..=max.0 {
+            for col in min.1..=max.1 {
+                let loc = UnboundLocation(row, col);
+                print!("{}", to_char(self.get(&loc), loc));
+            }
+            println!();
+        }
+    }
+}
+
+impl<T: Clone + Default> From<&Map<T>> for SparseMap<T> {
+    fn from(other: &Map<T>) -> Self {
+        Self::from_map(other, T::default(), |_, _| true)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::grid::{Location, Map, MapResult};
+
+#[derive(Debug)]
+pub struct MapView<'a, T> {
+    map: &'a Map<T>,
+    origin: Location,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Clone for MapView<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map,
+            origin: self.origin.clone(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<'a, T> MapView<'a, T> {
+    pub fn origin(&self) -> &Location {
+        &self.origin
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn to_global(&self, location: &Location) -> Location {
+        Location(self.origin.0 + location.0, self.origin.1 + location.1)
+    }
+
+    pub fn get(&self, location: &Location) -> &'a T {
+        assert!(location.0 < self.rows && location.1 < self.cols);
+        self.map.get(&self.to_global(location))
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        &self.map.0[self.origin.0 + row][self.origin.1..self.origin.1 + self.cols]
+    }
+
+    pub fn row_slices(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.rows).map(|r| self.row(r))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &'a T)> + '_ {
+        self.row_slices().enumerate().flat_map(|(row_num, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(col_num, value)| (Location(row_num, col_num), value))
+        })
+    }
+
+    pub fn find<F>(&self, match_fn: F) -> Option<Location>
+    where
+        F: Fn(&(Location, &T)) -> bool,
+    {
+        self.iter().find(match_fn).map(|o| o.0)
+    }
+
+    pub fn to_map(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map(self.row_slices().map(|r| r.to_vec()).collect())
+    }
+}
+
+impl<T: PartialEq> PartialEq for MapView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && self.row_slices().eq(other.row_slices())
+    }
+}
+
+impl<T: Eq> Eq for MapView<'_, T> {}
+
+impl<T> Map<T> {
+    pub fn view(&self, origin: Location, rows: usize, cols: usize) -> MapResult<MapView<'_, T>> {
+        if rows > 0 && cols > 0 {
+            self.get_location(origin.0 + rows - 1, origin.1 + cols - 1)?;
+        }
+        Ok(MapView {
+            map: self,
+            origin,
+            rows,
+            cols,
+        })
+    }
+
+    pub fn windows(&self, rows: usize, cols: usize) -> WindowIterator<'_, T> {
+        WindowIterator {
+            map: self,
+            next: Location(0, 0),
+            rows,
+            cols,
+        }
+    }
+}
+
+pub struct WindowIterator<'a, T> {
+    map: &'a Map<T>,
+    next: Location,
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> Iterator for WindowIterator<'a, T> {
+    type Item = MapView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.map.width()?;
+        if self.rows == 0 || self.cols == 0 || self.cols > width {
+            return None;
+        }
+        if self.next.0 + self.rows > self.map.0.len() {
+            return None;
+        }
+        let origin = self.next.clone();
+        self.next = if origin.1 + self.cols < width {
+            Location(origin.0, origin.1 + 1)
+        } else {
+            Location(origin.0 + 1, 0)
+        };
+        Some(MapView {
+            map: self.map,
+            origin,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+}
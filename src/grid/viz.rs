@@ -0,0 +1,163 @@
+use crate::grid::{Location, Map};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+const RESET: &str = "\x1b[0m";
+const FRAME_HEADER: &str = "frame ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn escape(&self) -> String {
+        match self {
+            Self::Default => "\x1b[39m".to_string(),
+            Self::Black => "\x1b[30m".to_string(),
+            Self::Red => "\x1b[31m".to_string(),
+            Self::Green => "\x1b[32m".to_string(),
+            Self::Yellow => "\x1b[33m".to_string(),
+            Self::Blue => "\x1b[34m".to_string(),
+            Self::Magenta => "\x1b[35m".to_string(),
+            Self::Cyan => "\x1b[36m".to_string(),
+            Self::White => "\x1b[37m".to_string(),
+            Self::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        }
+    }
+}
+
+impl<T> Map<T> {
+    pub fn render_coloured<F>(&self, to_cell: F) -> String
+    where
+        F: Fn(&T, Location) -> (char, Color),
+    {
+        let mut out = String::new();
+        for row in self.iter() {
+            let mut current = Color::Default;
+            for (loc, value) in row {
+                let (c, color) = to_cell(value, loc);
+                if color != current {
+                    out.push_str(&color.escape());
+                    current = color;
+                }
+                out.push(c);
+            }
+            out.push_str(RESET);
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn print_coloured<F>(&self, to_cell: F)
+    where
+        F: Fn(&T, Location) -> (char, Color),
+    {
+        print!("{}", self.render_coloured(to_cell));
+    }
+}
+
+pub struct Animator<W: Write> {
+    out: W,
+    delay: Duration,
+    recording: Option<BufWriter<File>>,
+    frame: usize,
+    last_height: usize,
+}
+
+impl Animator<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> Animator<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            delay: Duration::from_millis(100),
+            recording: None,
+            frame: 0,
+            last_height: 0,
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn record<P: AsRef<Path>>(mut self, path: P) -> io::Result<Self> {
+        self.recording = Some(BufWriter::new(File::create(path)?));
+        Ok(self)
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frame
+    }
+
+    pub fn frame<T, F>(&mut self, map: &Map<T>, to_cell: F) -> io::Result<()>
+    where
+        F: Fn(&T, Location) -> (char, Color),
+    {
+        if let Some(recording) = &mut self.recording {
+            writeln!(recording, "{}{}", FRAME_HEADER, self.frame)?;
+            write!(recording, "{}", map.render(|v, loc| to_cell(v, loc).0))?;
+        }
+        let rendered = map.render_coloured(to_cell);
+        self.draw(&rendered)
+    }
+
+    fn draw(&mut self, rendered: &str) -> io::Result<()> {
+        if self.last_height > 0 {
+            write!(self.out, "\x1b[{}F", self.last_height)?;
+        }
+        for line in rendered.lines() {
+            writeln!(self.out, "{}\x1b[K", line)?;
+        }
+        self.out.flush()?;
+        self.last_height = rendered.lines().count();
+        self.frame += 1;
+        std::thread::sleep(self.delay);
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(recording) = &mut self.recording {
+            recording.flush()?;
+        }
+        Ok(self.out)
+    }
+
+    pub fn playback<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut current = String::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.starts_with(FRAME_HEADER) {
+                if !current.is_empty() {
+                    self.draw(&current)?;
+                    current.clear();
+                }
+            } else {
+                current.push_str(&line);
+                current.push('\n');
+            }
+        }
+        if !current.is_empty() {
+            self.draw(&current)?;
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,9 @@
+pub mod bitset;
+pub mod geometry;
 pub mod grid;
+pub mod math;
+pub mod memo;
 pub mod parse;
+pub mod queue;
 pub mod ranges;
+pub mod vm;
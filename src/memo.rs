@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn get_or_insert_with<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+pub struct Memoized<A, K, V> {
+    memo: Memo<K, V>,
+    key: fn(&A) -> K,
+    body: fn(&mut Memoized<A, K, V>, A) -> V,
+}
+
+impl<A: Clone + Hash + Eq, V: Clone> Memoized<A, A, V> {
+    pub fn new(body: fn(&mut Memoized<A, A, V>, A) -> V) -> Self {
+        Self::with_key(A::clone, body)
+    }
+}
+
+impl<A, K: Hash + Eq, V: Clone> Memoized<A, K, V> {
+    pub fn with_key(key: fn(&A) -> K, body: fn(&mut Memoized<A, K, V>, A) -> V) -> Self {
+        Self {
+            memo: Memo::new(),
+            key,
+            body,
+        }
+    }
+
+    pub fn call(&mut self, arg: A) -> V {
+        let key = (self.key)(&arg);
+        if let Some(value) = self.memo.cache.get(&key) {
+            self.memo.hits += 1;
+            return value.clone();
+        }
+        self.memo.misses += 1;
+        let value = (self.body)(self, arg);
+        self.memo.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.memo.stats()
+    }
+
+    pub fn clear(&mut self) {
+        self.memo.clear()
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct MinQueue<T, P> {
+    heap: Vec<(P, T)>,
+    positions: HashMap<T, usize>,
+}
+
+impl<T, P> Default for MinQueue<T, P> {
+    fn default() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone, P: Ord + Copy> MinQueue<T, P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.positions.contains_key(item)
+    }
+
+    pub fn priority(&self, item: &T) -> Option<P> {
+        self.positions.get(item).map(|idx| self.heap[*idx].0)
+    }
+
+    pub fn peek_min(&self) -> Option<(&T, P)> {
+        self.heap.first().map(|(p, item)| (item, *p))
+    }
+
+    pub fn push_or_decrease(&mut self, item: T, priority: P) -> bool {
+        match self.positions.get(&item) {
+            Some(&idx) => {
+                if priority >= self.heap[idx].0 {
+                    return false;
+                }
+                self.heap[idx].0 = priority;
+                self.sift_up(idx);
+            }
+            None => {
+                let idx = self.heap.len();
+                self.positions.insert(item.clone(), idx);
+                self.heap.push((priority, item));
+                self.sift_up(idx);
+            }
+        }
+        true
+    }
+
+    pub fn pop_min(&mut self) -> Option<(T, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (priority, item) = self.heap.pop()?;
+        self.positions.remove(&item);
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((item, priority))
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions.insert(self.heap[a].1.clone(), a);
+        self.positions.insert(self.heap[b].1.clone(), b);
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.heap[idx].0 >= self.heap[parent].0 {
+                break;
+            }
+            self.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let mut smallest = idx;
+            for child in [2 * idx + 1, 2 * idx + 2] {
+                if child < self.heap.len() && self.heap[child].0 < self.heap[smallest].0 {
+                    smallest = child;
+                }
+            }
+            if smallest == idx {
+                return;
+            }
+            self.swap(idx, smallest);
+            idx = smallest;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+    len: usize,
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self {
+            buckets: Vec::new(),
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, item: T, cost: usize) {
+        if cost >= self.buckets.len() {
+            self.buckets.resize_with(cost + 1, Vec::new);
+        }
+        self.buckets[cost].push(item);
+        self.current = self.current.min(cost);
+        self.len += 1;
+    }
+
+    pub fn pop_min(&mut self) -> Option<(T, usize)> {
+        while self.current < self.buckets.len() {
+            if let Some(item) = self.buckets[self.current].pop() {
+                self.len -= 1;
+                return Some((item, self.current));
+            }
+            self.current += 1;
+        }
+        None
+    }
+
+    pub fn clear(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            bucket.clear();
+        }
+        self.current = 0;
+        self.len = 0;
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone)]
 pub struct InclusiveRange<A, B = A> {
     pub start: A,
     pub end: B,
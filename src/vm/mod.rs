@@ -0,0 +1,217 @@
+pub mod parse;
+
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, thiserror::Error)]
+pub enum VmError {
+    #[error("Invalid register {0}")]
+    InvalidRegister(usize),
+    #[error("Jump from {pc} by {offset} leaves the program")]
+    InvalidJump { pc: usize, offset: i64 },
+    #[error("Division by zero at {0}")]
+    DivideByZero(usize),
+    #[error("Invalid instruction at {pc}: {reason}")]
+    Invalid { pc: usize, reason: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(usize),
+    Immediate(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Control {
+    Next,
+    Jump(usize),
+    Relative(i64),
+    Output(i64),
+    WaitInput,
+    Halt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Running,
+    Output(i64),
+    NeedsInput,
+    Breakpoint(usize),
+    Halted,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Cpu {
+    pub registers: Vec<i64>,
+    pub pc: usize,
+    pub input: VecDeque<i64>,
+    pub output: VecDeque<i64>,
+}
+
+impl Cpu {
+    pub fn new(registers: usize) -> Self {
+        Self {
+            registers: vec![0; registers],
+            ..Default::default()
+        }
+    }
+
+    pub fn register(&self, idx: usize) -> Result<i64, VmError> {
+        self.registers
+            .get(idx)
+            .copied()
+            .ok_or(VmError::InvalidRegister(idx))
+    }
+
+    pub fn register_mut(&mut self, idx: usize) -> Result<&mut i64, VmError> {
+        self.registers
+            .get_mut(idx)
+            .ok_or(VmError::InvalidRegister(idx))
+    }
+
+    pub fn value(&self, operand: &Operand) -> Result<i64, VmError> {
+        match operand {
+            Operand::Register(idx) => self.register(*idx),
+            Operand::Immediate(value) => Ok(*value),
+        }
+    }
+
+    pub fn read_input(&mut self) -> Option<i64> {
+        self.input.pop_front()
+    }
+}
+
+pub trait Instruction {
+    fn execute(&self, cpu: &mut Cpu) -> Result<Control, VmError>;
+}
+
+type Tracer<I> = Box<dyn FnMut(&I, &Cpu)>;
+
+pub struct Machine<I> {
+    program: Vec<I>,
+    cpu: Cpu,
+    breakpoints: HashSet<usize>,
+    tracer: Option<Tracer<I>>,
+    paused_at: Option<usize>,
+    steps: usize,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>, registers: usize) -> Self {
+        Self {
+            program,
+            cpu: Cpu::new(registers),
+            breakpoints: HashSet::new(),
+            tracer: None,
+            paused_at: None,
+            steps: 0,
+        }
+    }
+
+    pub fn program(&self) -> &[I] {
+        &self.program
+    }
+
+    pub fn cpu(&self) -> &Cpu {
+        &self.cpu
+    }
+
+    pub fn cpu_mut(&mut self) -> &mut Cpu {
+        &mut self.cpu
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.cpu.input.push_back(value);
+    }
+
+    pub fn drain_output(&mut self) -> Vec<i64> {
+        self.cpu.output.drain(..).collect()
+    }
+
+    pub fn set_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn clear_breakpoint(&mut self, pc: usize) -> bool {
+        self.breakpoints.remove(&pc)
+    }
+
+    pub fn set_tracer<F>(&mut self, tracer: F)
+    where
+        F: FnMut(&I, &Cpu) + 'static,
+    {
+        self.tracer = Some(Box::new(tracer));
+    }
+
+    pub fn clear_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    pub fn step(&mut self) -> Result<Event, VmError> {
+        let pc = self.cpu.pc;
+        let Some(instruction) = self.program.get(pc) else {
+            return Ok(Event::Halted);
+        };
+        if let Some(tracer) = &mut self.tracer {
+            tracer(instruction, &self.cpu);
+        }
+
+        let control = instruction.execute(&mut self.cpu)?;
+        self.steps += 1;
+        self.paused_at = None;
+        Ok(match control {
+            Control::Next => {
+                self.cpu.pc += 1;
+                Event::Running
+            }
+            Control::Jump(target) => {
+                self.cpu.pc = target;
+                Event::Running
+            }
+            Control::Relative(offset) => {
+                self.cpu.pc = pc
+                    .checked_add_signed(offset as isize)
+                    .ok_or(VmError::InvalidJump { pc, offset })?;
+                Event::Running
+            }
+            Control::Output(value) => {
+                self.cpu.output.push_back(value);
+                self.cpu.pc += 1;
+                Event::Output(value)
+            }
+            Control::WaitInput => {
+                self.steps -= 1;
+                Event::NeedsInput
+            }
+            Control::Halt => Event::Halted,
+        })
+    }
+
+    fn run<F>(&mut self, stop: F) -> Result<Event, VmError>
+    where
+        F: Fn(&Event) -> bool,
+    {
+        loop {
+            let pc = self.cpu.pc;
+            if self.breakpoints.contains(&pc) && self.paused_at != Some(pc) {
+                self.paused_at = Some(pc);
+                return Ok(Event::Breakpoint(pc));
+            }
+            let event = self.step()?;
+            if stop(&event) {
+                return Ok(event);
+            }
+        }
+    }
+
+    pub fn run_until_halt(&mut self) -> Result<Event, VmError> {
+        self.run(|event| matches!(event, Event::Halted | Event::NeedsInput))
+    }
+
+    pub fn run_until_output(&mut self) -> Result<Event, VmError> {
+        self.run(|event| !matches!(event, Event::Running))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::parse::Parser;
+use crate::vm::Operand;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OpLineError {
+    #[error("Empty instruction line")]
+    Empty,
+    #[error("Invalid operand {0:?}")]
+    InvalidOperand(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpLine {
+    pub op: String,
+    pub args: Vec<Operand>,
+}
+
+impl OpLine {
+    pub fn arg(&self, idx: usize) -> Option<&Operand> {
+        self.args.get(idx)
+    }
+}
+
+pub struct ParseOperand;
+
+impl Parser<&str, Operand, OpLineError> for ParseOperand {
+    fn parse_section(&self, section: &str) -> Result<Operand, OpLineError> {
+        let section = section.trim().trim_end_matches(',');
+        let mut chars = section.chars();
+        if let (Some(c @ 'a'..='z'), None) = (chars.next(), chars.next()) {
+            return Ok(Operand::Register((c as u8 - b'a') as usize));
+        }
+        section
+            .trim_start_matches('+')
+            .parse()
+            .map(Operand::Immediate)
+            .map_err(|_| OpLineError::InvalidOperand(section.to_string()))
+    }
+}
+
+pub struct ParseOpLine;
+
+impl Parser<&str, OpLine, OpLineError> for ParseOpLine {
+    fn parse_section(&self, section: &str) -> Result<OpLine, OpLineError> {
+        let mut parts = section.split_whitespace();
+        let op = parts.next().ok_or(OpLineError::Empty)?.to_string();
+        let args = parts
+            .map(|part| ParseOperand.parse_section(part))
+            .collect::<Result<_, _>>()?;
+        Ok(OpLine { op, args })
+    }
+}